@@ -68,58 +68,225 @@ pub fn derive_smart_default(input: proc_macro::TokenStream) -> proc_macro::Token
         Ok(output) => {
             output.into()
         },
-        Err(error) =>{
-            error.to_compile_error().into()
+        Err(errors) =>{
+            errors.into()
         }
     }
 }
 
-fn impl_my_derive(input: &DeriveInput) -> Result<TokenStream, Error> {
+fn impl_my_derive(input: &DeriveInput) -> Result<TokenStream, TokenStream> {
     let name = &input.ident;
     let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
 
+    // Collect every malformed-attribute error instead of bailing on the first, so the user sees
+    // all the problems in a single compile. The errors are folded together at the end.
+    let mut errors = Vec::new();
+
+    // The fields whose defaults are actually emitted - struct fields, or the chosen enum variant's
+    // fields. Used below to infer `Default` bounds for the generic parameters they rely on.
+    let mut default_fields: Option<&syn::Fields> = None;
+
+    // The path used to construct the default value, so an optional `new` constructor can reuse it.
+    let mut ctor_path = quote! { Self };
+
     let (default_expr, doc) = match input.data {
         syn::Data::Struct(ref body) => {
-            let (body_assignment, doc) = default_body_tt(&body.fields)?;
+            default_fields = Some(&body.fields);
+            let (body_assignment, doc) = default_body_tt(&body.fields, &mut errors);
             (quote! {
                 #name #body_assignment
             }, format!("Return `{}{}`", name, doc))
         }
         syn::Data::Enum(ref body) => {
-            let default_variant = find_only(body.variants.iter(), |variant| {
-                if let Some(meta) = find_default_attr_value(&variant.attrs)? {
-                    if meta.is_none() {
-                        Ok(true)
-                    } else {
-                        Err(Error::new(meta.span(), "Attribute #[default] on variants should have no value"))
+            let mut default_variant = None;
+            for variant in body.variants.iter() {
+                match find_default_attr_value(&variant.attrs, &mut errors) {
+                    Some(None) => {
+                        if default_variant.is_some() {
+                            errors.push(Error::new(variant.span(), "Multiple defaults"));
+                        } else {
+                            default_variant = Some(variant);
+                        }
                     }
-                } else {
-                    Ok(false)
+                    Some(Some(meta)) => {
+                        errors.push(Error::new(meta.span(), "Attribute #[default] on variants should have no value"));
+                    }
+                    None => {}
                 }
-            })?.ok_or_else(|| Error::new(input.span(), "No default variant"))?;
-            let default_variant_name = &default_variant.ident;
-            let (body_assignment, doc) = default_body_tt(&default_variant.fields)?;
-            (quote! {
-                #name :: #default_variant_name #body_assignment
-            }, format!("Return `{}::{}{}`", name, default_variant_name, doc))
+            }
+            if let Some(default_variant) = default_variant {
+                detect_non_default_variant_default_attrs(body.variants.iter(), default_variant, &mut errors);
+                default_fields = Some(&default_variant.fields);
+                let default_variant_name = &default_variant.ident;
+                ctor_path = quote! { Self :: #default_variant_name };
+                let (body_assignment, doc) = default_body_tt(&default_variant.fields, &mut errors);
+                (quote! {
+                    #name :: #default_variant_name #body_assignment
+                }, format!("Return `{}::{}{}`", name, default_variant_name, doc))
+            } else {
+                errors.push(Error::new(input.span(), "No default variant"));
+                (quote! { #name }, String::new())
+            }
         }
         syn::Data::Union(_) => {
             panic!()
         }
     };
+
+    if !errors.is_empty() {
+        // Emit every accumulated error at once so the user sees them all in a single compile.
+        let compile_errors = errors.iter().map(|error| error.to_compile_error());
+        return Err(quote! { #( #compile_errors )* });
+    }
+
+    // Just like the standard `#[derive(Default)]`, add a `T: Default` bound for every type
+    // parameter that a field without an explicit `#[default(...)]` relies on - those fields expand
+    // to `Default::default()` and therefore need `Default` in scope. Fields with an explicit value
+    // do not contribute a bound, since their value need not be `Default`. Duplicating a bound the
+    // user wrote by hand is harmless in Rust.
+    let type_params: std::collections::HashSet<String> =
+        input.generics.type_params().map(|tp| tp.ident.to_string()).collect();
+    let mut needed = std::collections::HashSet::new();
+    if let Some(fields) = default_fields {
+        for field in fields.iter() {
+            if find_default_attr_value(&field.attrs, &mut Vec::new()).is_none() {
+                collect_type_params(field.ty.clone().into_token_stream(), &type_params, &mut needed);
+            }
+        }
+    }
+    let default_where_clause = {
+        let mut default_where_clause = where_clause.cloned();
+        if !needed.is_empty() {
+            let default_where_clause = default_where_clause.get_or_insert_with(|| syn::WhereClause {
+                where_token: Default::default(),
+                predicates: syn::punctuated::Punctuated::new(),
+            });
+            for type_param in input.generics.type_params() {
+                if needed.contains(&type_param.ident.to_string()) {
+                    let ident = &type_param.ident;
+                    default_where_clause.predicates.push(syn::parse_quote!(#ident: ::core::default::Default));
+                }
+            }
+        }
+        default_where_clause
+    };
+
+    // Optional `new(...)` constructor taking exactly the fields that lack a `#[default]` and
+    // filling the rest from their defaults. It uses the user's own bounds rather than the inferred
+    // `Default` ones, since its arguments are passed in instead of defaulted.
+    let new_impl = if has_with_new_attr(&input.attrs) {
+        let fields = default_fields.unwrap_or(&syn::Fields::Unit);
+        let (params, body, arg_docs) = new_constructor(&ctor_path, fields);
+        let doc = if arg_docs.is_empty() {
+            format!("Create a new `{}`, using the `SmartDefault` defaults for every field.", name)
+        } else {
+            format!("Create a new `{}` from {}, using the `SmartDefault` defaults for the remaining fields.", name, arg_docs.join(", "))
+        };
+        quote! {
+            impl #impl_generics #name #ty_generics #where_clause {
+                #[doc = #doc]
+                pub fn new(#( #params ),*) -> Self {
+                    #body
+                }
+            }
+        }
+    } else {
+        quote! {}
+    };
+
     Ok(quote! {
-        impl #impl_generics Default for #name #ty_generics #where_clause {
+        impl #impl_generics Default for #name #ty_generics #default_where_clause {
             #[doc = #doc]
             fn default() -> Self {
                 #default_expr
             }
         }
+
+        #new_impl
     })
 }
 
+/// Whether the input carries a type-level `#[default(with_new)]` attribute, requesting a generated
+/// partial constructor.
+fn has_with_new_attr(attrs: &[syn::Attribute]) -> bool {
+    attrs.iter().any(|attr| {
+        if !matches!(is_default_attr(attr), Ok(true)) {
+            return false;
+        }
+        if let Ok(syn::Meta::List(meta)) = attr.parse_meta() {
+            meta.nested.iter().any(|nested| {
+                matches!(nested, syn::NestedMeta::Meta(syn::Meta::Word(ident)) if ident == "with_new")
+            })
+        } else {
+            false
+        }
+    })
+}
+
+/// Build the argument list, constructor body and argument-documentation for a `new(...)`
+/// constructor over `fields`: fields with an explicit default are filled inline, the rest become
+/// named parameters in declaration order.
+fn new_constructor(ctor_path: &TokenStream, fields: &syn::Fields) -> (Vec<TokenStream>, TokenStream, Vec<String>) {
+    let mut params = Vec::new();
+    let mut arg_docs = Vec::new();
+    let body = match fields {
+        &syn::Fields::Named(ref fields) => {
+            let assignments = fields.named.iter().map(|field| {
+                let field_name = field.ident.as_ref();
+                match find_default_attr_value(&field.attrs, &mut Vec::new()) {
+                    Some(Some(field_value)) => quote! { #field_name : #field_value },
+                    _ => {
+                        let ty = &field.ty;
+                        params.push(quote! { #field_name : #ty });
+                        arg_docs.push(format!("`{}`", field_name.expect("named field without name")));
+                        quote! { #field_name }
+                    }
+                }
+            }).collect::<Vec<_>>();
+            quote! { #ctor_path { #( #assignments ),* } }
+        }
+        &syn::Fields::Unnamed(ref fields) => {
+            let values = fields.unnamed.iter().enumerate().map(|(index, field)| {
+                match find_default_attr_value(&field.attrs, &mut Vec::new()) {
+                    Some(Some(field_value)) => field_value,
+                    _ => {
+                        let ty = &field.ty;
+                        let param = syn::Ident::new(&format!("field{}", index), proc_macro2::Span::call_site());
+                        params.push(quote! { #param : #ty });
+                        arg_docs.push(format!("`{}`", param));
+                        quote! { #param }
+                    }
+                }
+            }).collect::<Vec<_>>();
+            quote! { #ctor_path ( #( #values ),* ) }
+        }
+        &syn::Fields::Unit => quote! { #ctor_path },
+    };
+    (params, body, arg_docs)
+}
+
+/// Collect into `found` every identifier in `tokens` that names one of the `type_params`. Used to
+/// discover which generic type parameters a field's type depends on.
+fn collect_type_params(tokens: TokenStream, type_params: &std::collections::HashSet<String>, found: &mut std::collections::HashSet<String>) {
+    for tt in tokens {
+        match tt {
+            proc_macro2::TokenTree::Ident(ident) => {
+                let ident = ident.to_string();
+                if type_params.contains(&ident) {
+                    found.insert(ident);
+                }
+            }
+            proc_macro2::TokenTree::Group(group) => {
+                collect_type_params(group.stream(), type_params, found);
+            }
+            _ => {}
+        }
+    }
+}
+
 /// Return a token-tree for the default "body" - the part after the name that contains the values.
 /// That is, the `{ ... }` part for structs, the `(...)` part for tuples, and nothing for units.
-fn default_body_tt(body: &syn::Fields) -> Result<(TokenStream, String), Error> {
+fn default_body_tt(body: &syn::Fields, errors: &mut Vec<Error>) -> (TokenStream, String) {
     let mut doc = String::new();
     use std::fmt::Write;
     let body_tt = match body {
@@ -128,11 +295,11 @@ fn default_body_tt(body: &syn::Fields) -> Result<(TokenStream, String), Error> {
             let result = {
                 let field_assignments = fields.named.iter().map(|field| {
                     let field_name = field.ident.as_ref();
-                    let (default_value, default_doc) = field_default_expr_and_doc(field)?;
+                    let (default_value, default_doc) = field_default_expr_and_doc(field, errors);
                     write!(&mut doc, "\n    {}: {},", field_name.expect("field value in struct is empty"), default_doc).unwrap();
                     // let default_value = default_value.into_token_stream();
-                    Ok(quote! { #field_name : #default_value })
-                }).collect::<Result<Vec<_>, Error>>()?;
+                    quote! { #field_name : #default_value }
+                }).collect::<Vec<_>>();
                 quote!{
                     {
                         #( #field_assignments ),*
@@ -150,10 +317,10 @@ fn default_body_tt(body: &syn::Fields) -> Result<(TokenStream, String), Error> {
             doc.push('(');
             let result = {
                 let field_assignments = fields.unnamed.iter().map(|field| {
-                    let (default_value, default_doc) = field_default_expr_and_doc(field)?;
+                    let (default_value, default_doc) = field_default_expr_and_doc(field, errors);
                     write!(&mut doc, "{}, ", default_doc).unwrap();
-                    Ok(default_value)
-                }).collect::<Result<Vec<TokenStream>, Error>>()?;
+                    default_value
+                }).collect::<Vec<TokenStream>>();
                 quote! {
                     (
                         #( #field_assignments ),*
@@ -169,21 +336,50 @@ fn default_body_tt(body: &syn::Fields) -> Result<(TokenStream, String), Error> {
         }
         &syn::Fields::Unit => quote!{},
     };
-    Ok((body_tt, doc))
+    (body_tt, doc)
 }
 
-/// Return a default expression for a field based on it's `#[default = "..."]` attribute. Panic
-/// if there is more than one, of if there is a `#[default]` attribute without value.
-fn field_default_expr_and_doc(field: &syn::Field) -> Result<(TokenStream, String), Error> {
-    if let Some(field_value) = find_default_attr_value(&field.attrs)? {
-        let field_value = field_value.ok_or_else(|| {
-            Error::new(field.span(), "Expected #[default = ...] or #[default(...)]")})?;
-        let field_doc = format!("{}", field_value);
-        Ok((field_value, field_doc))
-    } else {
-        Ok((quote! {
+/// Return a default expression for a field based on it's `#[default = "..."]` attribute. Record an
+/// error if there is more than one (the first one's value is then used), or if there is a
+/// `#[default]` attribute without value (falling back to a dummy `Default::default()` so code
+/// generation can continue).
+fn field_default_expr_and_doc(field: &syn::Field, errors: &mut Vec<Error>) -> (TokenStream, String) {
+    match find_default_attr_value(&field.attrs, errors) {
+        Some(Some(field_value)) => {
+            let field_doc = format!("{}", field_value);
+            (field_value, field_doc)
+        }
+        Some(None) => {
+            errors.push(Error::new(field.span(), "Expected #[default = ...] or #[default(...)]"));
+            (quote! { ::core::default::Default::default() }, "Default::default()".to_owned())
+        }
+        None => (quote! {
             Default::default()
-        }, "Default::default()".to_owned()))
+        }, "Default::default()".to_owned()),
+    }
+}
+
+/// Only the default variant's fields are fed through `default_body_tt`, so a `#[default = ...]` on
+/// a field of any other variant is silently ignored. Walk every non-default variant's fields and
+/// report such attributes, mirroring the `DetectNonVariantDefaultAttr` check that the standard
+/// library's `#[derive(Default)]` performs (RFC 3107). The valueless `#[default]` marker on the
+/// default variant itself is legitimate and is handled by the caller.
+fn detect_non_default_variant_default_attrs<'a>(
+    variants: impl Iterator<Item = &'a syn::Variant>,
+    default_variant: &syn::Variant,
+    errors: &mut Vec<Error>,
+) {
+    for variant in variants {
+        if std::ptr::eq(variant, default_variant) {
+            continue;
+        }
+        for field in variant.fields.iter() {
+            for attr in field.attrs.iter() {
+                if let Ok(true) = is_default_attr(attr) {
+                    errors.push(Error::new(attr.span(), "#[default] attribute has no effect outside the default variant"));
+                }
+            }
+        }
     }
 }
 
@@ -205,55 +401,110 @@ fn is_default_attr(attr: &syn::Attribute) -> Result<bool, Error> {
     Ok(segment.ident.to_string() == "default")
 }
 
-fn find_default_attr_value(attrs: &[syn::Attribute]) -> Result<Option<Option<TokenStream>>, Error> {
-    if let Some(default_attr) = find_only(attrs.iter(), |attr| is_default_attr(attr))? {
-        match default_attr.parse_meta() {
-            Ok(syn::Meta::Word(_)) => Ok(Some(None)),
-            Ok(syn::Meta::List(meta)) => {
-                if let Some(field_value) = single_value(meta.nested.iter()) {
-                    Ok(Some(Some(field_value.into_token_stream())))
+/// Recognise a `#[default(into = <tokens>)]` / `#[default(no_into = <tokens>)]` directive in the
+/// raw attribute tokens (`( into = ... )`). Returns `Some((true, rhs))` for `into`, `Some((false,
+/// rhs))` for `no_into`, and `None` for any other `#[default(...)]` contents. The right-hand side
+/// is returned unparsed so it can be any expression.
+fn parse_conversion_directive(tts: &TokenStream) -> Option<(bool, TokenStream)> {
+    let mut trees = tts.clone().into_iter();
+    let group = match trees.next() {
+        Some(proc_macro2::TokenTree::Group(group)) => group,
+        _ => return None,
+    };
+    if trees.next().is_some() {
+        return None;
+    }
+    let mut inner = group.stream().into_iter();
+    let convert = match inner.next() {
+        Some(proc_macro2::TokenTree::Ident(ref ident)) if ident == "into" => true,
+        Some(proc_macro2::TokenTree::Ident(ref ident)) if ident == "no_into" => false,
+        _ => return None,
+    };
+    match inner.next() {
+        Some(proc_macro2::TokenTree::Punct(ref punct)) if punct.as_char() == '=' => {}
+        _ => return None,
+    }
+    let rhs: TokenStream = inner.collect();
+    if rhs.is_empty() {
+        return None;
+    }
+    Some((convert, rhs))
+}
+
+fn find_default_attr_value(attrs: &[syn::Attribute], errors: &mut Vec<Error>) -> Option<Option<TokenStream>> {
+    let default_attr = find_only(attrs.iter(), errors, |attr| is_default_attr(attr))?;
+    // `#[default(into = <expr>)]` forces an `Into::into` wrap and `#[default(no_into = <expr>)]`
+    // keeps the expression verbatim. These are parsed straight from the attribute tokens so the
+    // right-hand side may be any expression, not just a literal.
+    if let Some((convert, expr)) = parse_conversion_directive(&default_attr.tts) {
+        return match syn::parse2::<syn::Expr>(expr) {
+            Ok(expr) => {
+                let expr = expr.into_token_stream();
+                if convert {
+                    Some(Some(quote! { ::core::convert::Into::into(#expr) }))
                 } else {
-                    return Err(Error::new(
-                            if meta.nested.is_empty() {
-                                meta.span()
-                            } else {
-                                meta.nested.span()
-                            },
-                            "Expected signle value in #[default(...)]"));
+                    Some(Some(expr))
                 }
             }
-            Ok(syn::Meta::NameValue(meta)) => {
-                Ok(Some(Some(meta.lit.into_token_stream())))
-            }
             Err(error) => {
-                if let syn::Expr::Paren(as_parens) = syn::parse(default_attr.tts.clone().into())? {
-                    Ok(Some(Some(as_parens.expr.into_token_stream())))
-                } else {
-                    Err(error)
+                errors.push(error);
+                None
+            }
+        };
+    }
+    match default_attr.parse_meta() {
+        Ok(syn::Meta::Word(_)) => Some(None),
+        Ok(syn::Meta::List(meta)) => {
+            if let Some(field_value) = single_value(meta.nested.iter()) {
+                Some(Some(field_value.into_token_stream()))
+            } else {
+                errors.push(Error::new(
+                        if meta.nested.is_empty() {
+                            meta.span()
+                        } else {
+                            meta.nested.span()
+                        },
+                        "Expected signle value in #[default(...)]"));
+                None
+            }
+        }
+        Ok(syn::Meta::NameValue(meta)) => {
+            Some(Some(meta.lit.into_token_stream()))
+        }
+        Err(error) => {
+            match syn::parse(default_attr.tts.clone().into()) {
+                Ok(syn::Expr::Paren(as_parens)) => Some(Some(as_parens.expr.into_token_stream())),
+                _ => {
+                    errors.push(error);
+                    None
                 }
             }
         }
-    } else {
-        Ok(None)
     }
 }
 
-/// Return the value that fulfills the predicate if there is one in the slice. Panic if there is
-/// more than one.
-fn find_only<T, F>(iter: impl Iterator<Item = T>, pred: F) -> Result<Option<T>, Error>
+/// Return the value that fulfills the predicate if there is one in the slice. Record a "Multiple
+/// defaults" error for every extra match (and any error the predicate produces) rather than
+/// bailing, so the caller can keep accumulating problems.
+fn find_only<T, F>(iter: impl Iterator<Item = T>, errors: &mut Vec<Error>, pred: F) -> Option<T>
 where T: Spanned,
       F: Fn(&T) -> Result<bool, Error>,
 {
     let mut result = None;
     for item in iter {
-        if pred(&item)? {
-            if result.is_some() {
-                return Err(Error::new(item.span(), "Multiple defaults"));
+        match pred(&item) {
+            Ok(true) => {
+                if result.is_some() {
+                    errors.push(Error::new(item.span(), "Multiple defaults"));
+                } else {
+                    result = Some(item);
+                }
             }
-            result = Some(item);
+            Ok(false) => {}
+            Err(err) => errors.push(err),
         }
     }
-    Ok(result)
+    result
 }
 
 fn single_value<T>(mut it: impl Iterator<Item = T>) -> Option<T> {
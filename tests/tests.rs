@@ -114,6 +114,18 @@ fn test_generics_type_parameters() {
     assert_eq!(Foo::default(), Foo { x: Some(0) });
 }
 
+#[test]
+fn test_generics_inferred_default_bound() {
+    // No hand-written `where T: Default` - the bound is inferred because `x` has no `#[default]`
+    // and therefore expands to `Default::default()`.
+    #[derive(Debug, PartialEq, SmartDefault)]
+    struct Foo<T> {
+        x: T,
+    }
+
+    assert_eq!(Foo::<i32>::default(), Foo { x: 0 });
+}
+
 #[test]
 fn test_generics_lifetime_parameters() {
     // NOTE: A default value makes no sense with lifetime parameters, since ::default() receives no
@@ -150,6 +162,38 @@ fn test_string_conversion() {
     assert_eq!(Foo::default(), Foo("one", "two".to_owned()));
 }
 
+#[test]
+fn test_explicit_conversion() {
+    #[derive(Debug, PartialEq, SmartDefault)]
+    struct Foo(
+        // Wrap the value in `Into::into` explicitly.
+        #[default(into = "one")] String,
+        // Keep the value as written, without an `Into::into` wrap.
+        #[default(no_into = "two")] &'static str,
+    );
+
+    assert_eq!(Foo::default(), Foo("one".to_owned(), "two"));
+}
+
+#[test]
+fn test_explicit_conversion_non_literal() {
+    #[derive(Debug, PartialEq)]
+    struct Meters(i32);
+
+    impl From<i32> for Meters {
+        fn from(value: i32) -> Self {
+            Meters(value)
+        }
+    }
+
+    const SEED: i32 = 7;
+
+    #[derive(Debug, PartialEq, SmartDefault)]
+    struct Foo(#[default(into = SEED)] Meters);
+
+    assert_eq!(Foo::default(), Foo(Meters(7)));
+}
+
 #[test] // https://github.com/idanarye/rust-smart-default/issues/13
 fn test_issue_13_bool() {
     #[derive(Debug, PartialEq, SmartDefault)]
@@ -187,3 +231,28 @@ fn test_issue_13_enum() {
         }
     );
 }
+
+#[test]
+fn test_with_new_struct() {
+    #[derive(Debug, PartialEq, SmartDefault)]
+    #[default(with_new)]
+    struct Foo {
+        #[default = 10]
+        x: i32,
+        #[default = 20]
+        y: i32,
+        // No default
+        z: i32,
+    }
+
+    assert_eq!(Foo::new(5), Foo { x: 10, y: 20, z: 5 });
+}
+
+#[test]
+fn test_with_new_tuple() {
+    #[derive(Debug, PartialEq, SmartDefault)]
+    #[default(with_new)]
+    struct Foo(#[default = 10] i32, i32);
+
+    assert_eq!(Foo::new(5), Foo(10, 5));
+}